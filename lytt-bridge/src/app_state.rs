@@ -1,9 +1,11 @@
+use std::sync::Arc;
+
 use reqwest::Client;
 
-use crate::config::Config;
+use crate::{auth::AuthState, backends::Backends, config::Config};
 
 /// Shared application state injected into every request handler via Axum's
-/// `State` extractor. Fields are cheap to clone because both `Config` and
+/// `State` extractor. Fields are cheap to clone because `Config` and
 /// `Client` are internally reference-counted.
 pub struct AppState {
     pub config: Config,
@@ -11,4 +13,8 @@ pub struct AppState {
     /// per request would open a new TLS handshake for every audio upload;
     /// sharing it reuses existing connections to api.openai.com.
     pub http_client: Client,
+    /// Every transcription provider configured at startup, keyed by name.
+    pub backends: Backends,
+    /// `None` when running with `--insecure` (no auth layer is mounted).
+    pub auth: Option<Arc<AuthState>>,
 }
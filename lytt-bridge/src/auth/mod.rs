@@ -0,0 +1,147 @@
+use std::{
+    collections::{HashMap, HashSet},
+    fs,
+    future::Future,
+    path::Path,
+    pin::Pin,
+    sync::Mutex,
+    task::{Context, Poll},
+    time::{Duration, Instant},
+};
+
+use anyhow::Context as _;
+use axum::{
+    body::Body,
+    http::{header::AUTHORIZATION, Request, StatusCode},
+    response::{IntoResponse, Response},
+    Json,
+};
+use serde_json::json;
+use tower::{Layer, Service};
+use uuid::Uuid;
+
+type BoxFuture<T> = Pin<Box<dyn Future<Output = T> + Send>>;
+
+/// Bearer tokens accepted by every route except `/health`: a persisted set
+/// loaded once from `--tokens-file`, plus scoped tokens minted via
+/// `POST /tokens/scoped` that live only in memory and expire after
+/// `--scoped-expiry-seconds`. Scoped tokens are deliberately not persisted —
+/// a restart revokes every one of them.
+pub struct AuthState {
+    persisted:  HashSet<String>,
+    scoped:     Mutex<HashMap<String, Instant>>,
+    scoped_ttl: Duration,
+}
+
+impl AuthState {
+    pub fn load(tokens_file: &Path, scoped_ttl: Duration) -> anyhow::Result<Self> {
+        let contents = fs::read_to_string(tokens_file)
+            .with_context(|| format!("Failed to read tokens file {}", tokens_file.display()))?;
+
+        let persisted = contents
+            .lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty() && !line.starts_with('#'))
+            .map(str::to_string)
+            .collect();
+
+        Ok(Self { persisted, scoped: Mutex::new(HashMap::new()), scoped_ttl })
+    }
+
+    fn is_valid(&self, token: &str) -> bool {
+        if self.persisted.contains(token) {
+            return true;
+        }
+
+        let mut scoped = self.scoped.lock().unwrap();
+        match scoped.get(token) {
+            Some(expires_at) if *expires_at > Instant::now() => true,
+            Some(_) => {
+                scoped.remove(token);
+                false
+            }
+            None => false,
+        }
+    }
+
+    /// Mint a new scoped token good for `scoped_ttl`. Expired entries are
+    /// swept out on every mint so the map can't grow unbounded.
+    pub fn mint_scoped(&self) -> (String, Duration) {
+        let token = format!("scoped_{}", Uuid::new_v4());
+
+        let mut scoped = self.scoped.lock().unwrap();
+        scoped.retain(|_, expires_at| *expires_at > Instant::now());
+        scoped.insert(token.clone(), Instant::now() + self.scoped_ttl);
+
+        (token, self.scoped_ttl)
+    }
+}
+
+/// Tower layer that rejects any request without a valid bearer token.
+/// Applied to every route except `/health`, which is mounted outside it.
+#[derive(Clone)]
+pub struct AuthLayer<T> {
+    state: T,
+}
+
+impl<T> AuthLayer<T> {
+    pub fn new(state: T) -> Self {
+        Self { state }
+    }
+}
+
+impl<S, T: Clone> Layer<S> for AuthLayer<T> {
+    type Service = AuthMiddleware<S, T>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        AuthMiddleware { inner, state: self.state.clone() }
+    }
+}
+
+#[derive(Clone)]
+pub struct AuthMiddleware<S, T> {
+    inner: S,
+    state: T,
+}
+
+impl<S, T> Service<Request<Body>> for AuthMiddleware<S, T>
+where
+    S: Service<Request<Body>, Response = Response> + Clone + Send + 'static,
+    S::Future: Send + 'static,
+    T: AsRef<AuthState> + Clone + Send + Sync + 'static,
+{
+    type Response = Response;
+    type Error = S::Error;
+    type Future = BoxFuture<Result<Response, S::Error>>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, req: Request<Body>) -> Self::Future {
+        let token = req
+            .headers()
+            .get(AUTHORIZATION)
+            .and_then(|v| v.to_str().ok())
+            .and_then(|v| v.strip_prefix("Bearer "))
+            .map(str::to_string);
+
+        let state = self.state.clone();
+        // `poll_ready` above was called on `self.inner`, not on a clone, so
+        // it's `self.inner` that's actually ready to be called — swap it out
+        // for the clone (which becomes ready on its own next `poll_ready`)
+        // rather than calling the unpolled clone directly.
+        let mut ready_inner = std::mem::replace(&mut self.inner, self.inner.clone());
+
+        Box::pin(async move {
+            match token {
+                Some(token) if state.as_ref().is_valid(&token) => ready_inner.call(req).await,
+                _ => Ok((
+                    StatusCode::UNAUTHORIZED,
+                    Json(json!({ "error": "Missing or invalid bearer token" })),
+                )
+                    .into_response()),
+            }
+        })
+    }
+}
@@ -0,0 +1,82 @@
+use async_trait::async_trait;
+use reqwest::{header::AUTHORIZATION, Client, StatusCode};
+use serde_json::Value;
+
+use crate::{error::AppError, retry::{send_with_retry, RetryPolicy}};
+
+use super::{TranscriptionBackend, TranscriptionResult};
+
+const LISTEN_URL: &str = "https://api.deepgram.com/v1/listen";
+const MODEL:      &str = "nova-2";
+
+/// Cloud transcription via Deepgram's `/v1/listen` endpoint — a faster,
+/// cheaper alternative to OpenAI Whisper and a failover path when it's down.
+pub struct DeepgramBackend {
+    http_client: Client,
+    api_key:     String,
+    retry:       RetryPolicy,
+}
+
+impl DeepgramBackend {
+    pub fn new(http_client: Client, api_key: String, retry: RetryPolicy) -> Self {
+        Self { http_client, api_key, retry }
+    }
+}
+
+#[async_trait]
+impl TranscriptionBackend for DeepgramBackend {
+    async fn transcribe(
+        &self,
+        bytes: Vec<u8>,
+        mime: &str,
+        lang: Option<&str>,
+        // Deepgram's response doesn't carry the segment timestamps Whisper's
+        // verbose_json does, so verbose output isn't supported — callers
+        // always get a plain transcript regardless of this flag.
+        _verbose: bool,
+    ) -> Result<TranscriptionResult, AppError> {
+        let mut query = vec![("model", MODEL.to_string()), ("smart_format", "true".to_string())];
+        if let Some(lang) = lang {
+            query.push(("language", lang.to_string()));
+        }
+
+        // Unlike the Whisper backends, Deepgram takes the raw audio bytes as
+        // the request body rather than a multipart form.
+        let response = send_with_retry(&self.retry, || async {
+            self.http_client
+                .post(LISTEN_URL)
+                .header(AUTHORIZATION, format!("Token {}", self.api_key))
+                .header(reqwest::header::CONTENT_TYPE, mime)
+                .query(&query)
+                .body(bytes.clone())
+                .send()
+                .await
+        })
+        .await
+        .map_err(|e| AppError::Transcription(format!("Deepgram request failed: {e}")))?;
+
+        if response.status() == StatusCode::TOO_MANY_REQUESTS {
+            let body = response.text().await.unwrap_or_default();
+            return Err(AppError::RateLimited(body));
+        }
+
+        if !response.status().is_success() {
+            let status = response.status();
+            let body   = response.text().await.unwrap_or_default();
+            return Err(AppError::Transcription(format!("Deepgram HTTP {status}: {body}")));
+        }
+
+        let body = response
+            .json::<Value>()
+            .await
+            .map_err(|e| AppError::Transcription(format!("Deepgram response parse failed: {e}")))?;
+
+        let text = body
+            .pointer("/results/channels/0/alternatives/0/transcript")
+            .and_then(|v| v.as_str())
+            .unwrap_or("")
+            .to_string();
+
+        Ok(TranscriptionResult::text_only(text))
+    }
+}
@@ -0,0 +1,241 @@
+use std::{path::Path, sync::Arc};
+
+use async_trait::async_trait;
+use whisper_rs::{FullParams, SamplingStrategy, WhisperContext, WhisperContextParameters};
+
+use crate::error::AppError;
+
+use super::{TranscriptSegment, TranscriptionBackend, TranscriptionResult};
+
+/// Offline transcription via a local whisper.cpp (ggml) model. Unlike
+/// [`OpenAiBackend`](super::openai::OpenAiBackend) this never leaves the
+/// machine, isn't subject to OpenAI's 25 MB upload cap, and needs no API
+/// key — at the cost of running inference on the host CPU. Uploads are still
+/// bounded by the server's 26 MB request body limit, as for every backend.
+pub struct LocalBackend {
+    context: Arc<WhisperContext>,
+}
+
+impl LocalBackend {
+    pub fn load(model_path: &Path) -> anyhow::Result<Self> {
+        let context = WhisperContext::new_with_params(
+            model_path
+                .to_str()
+                .ok_or_else(|| anyhow::anyhow!("model path is not valid UTF-8"))?,
+            WhisperContextParameters::default(),
+        )?;
+
+        Ok(Self { context: Arc::new(context) })
+    }
+}
+
+#[async_trait]
+impl TranscriptionBackend for LocalBackend {
+    async fn transcribe(
+        &self,
+        bytes: Vec<u8>,
+        mime: &str,
+        lang: Option<&str>,
+        verbose: bool,
+    ) -> Result<TranscriptionResult, AppError> {
+        let mime = mime.to_string();
+        let lang = lang.map(str::to_string);
+        let context = self.context.clone();
+
+        // whisper.cpp expects 16 kHz mono f32 PCM, never the raw webm/mp4 the
+        // browser records; and inference itself is CPU-bound, so both the
+        // decode and the model run happen off the Tokio runtime.
+        tokio::task::spawn_blocking(move || {
+            let samples = decode_to_16khz_mono(&bytes, &mime)?;
+
+            let mut state = context
+                .create_state()
+                .map_err(|e| AppError::Transcription(format!("whisper.cpp state init failed: {e}")))?;
+
+            let mut params = FullParams::new(SamplingStrategy::Greedy { best_of: 1 });
+            params.set_print_progress(false);
+            params.set_print_special(false);
+            params.set_print_realtime(false);
+            if let Some(lang) = lang.as_deref() {
+                params.set_language(Some(lang));
+            }
+
+            state
+                .full(params, &samples)
+                .map_err(|e| AppError::Transcription(format!("whisper.cpp inference failed: {e}")))?;
+
+            let segments = state
+                .full_n_segments()
+                .map_err(|e| AppError::Transcription(e.to_string()))?;
+
+            let mut text = String::new();
+            // whisper.cpp reports segment bounds in centiseconds (1/100 s).
+            let mut timestamped_segments = Vec::with_capacity(segments as usize);
+            for i in 0..segments {
+                let segment_text = state.full_get_segment_text(i).map_err(|e| AppError::Transcription(e.to_string()))?;
+                text.push_str(&segment_text);
+
+                if verbose {
+                    let t0 = state.full_get_segment_t0(i).map_err(|e| AppError::Transcription(e.to_string()))?;
+                    let t1 = state.full_get_segment_t1(i).map_err(|e| AppError::Transcription(e.to_string()))?;
+                    timestamped_segments.push(TranscriptSegment {
+                        start: t0 as f64 / 100.0,
+                        end:   t1 as f64 / 100.0,
+                        text:  segment_text.trim().to_string(),
+                    });
+                }
+            }
+
+            let text = text.trim().to_string();
+            if !verbose {
+                return Ok(TranscriptionResult::text_only(text));
+            }
+
+            let duration = timestamped_segments.last().map(|s| s.end);
+            Ok(TranscriptionResult {
+                text,
+                language: lang.clone(),
+                duration,
+                segments: Some(timestamped_segments),
+                words: None,
+            })
+        })
+        .await
+        .map_err(|e| AppError::Transcription(format!("local transcription task panicked: {e}")))?
+    }
+}
+
+/// Decode an arbitrary webm/mp4/opus blob into 16 kHz mono `f32` samples,
+/// the only format whisper.cpp accepts.
+///
+/// symphonia demuxes the container (WebM/Matroska, MP4, ...) but carries no
+/// Opus decoder of its own, and Opus is exactly what the browser's
+/// `MediaRecorder` produces by default (`audio/webm;codecs=opus`) — so that
+/// track is decoded by hand via libopus instead of `get_codecs().make(..)`.
+fn decode_to_16khz_mono(bytes: &[u8], mime: &str) -> Result<Vec<f32>, AppError> {
+    use symphonia::core::{
+        audio::SampleBuffer, codecs::{CODEC_TYPE_OPUS, DecoderOptions}, formats::FormatOptions,
+        io::MediaSourceStream, meta::MetadataOptions, probe::Hint,
+    };
+
+    let mut hint = Hint::new();
+    if let Some(ext) = mime.split('/').nth(1) {
+        hint.with_extension(ext);
+    }
+
+    let source = MediaSourceStream::new(Box::new(std::io::Cursor::new(bytes.to_vec())), Default::default());
+    let probed = symphonia::default::get_probe()
+        .format(&hint, source, &FormatOptions::default(), &MetadataOptions::default())
+        .map_err(|e| AppError::Transcription(format!("failed to probe audio: {e}")))?;
+
+    let mut format = probed.format;
+    let track = format
+        .default_track()
+        .ok_or_else(|| AppError::Transcription("audio blob has no decodable track".into()))?;
+
+    let track_id = track.id;
+
+    if track.codec_params.codec == CODEC_TYPE_OPUS {
+        let channels = track.codec_params.channels.map(|c| c.count()).unwrap_or(1).max(1);
+        return decode_opus_track(&mut *format, track_id, channels);
+    }
+
+    let source_rate  = track.codec_params.sample_rate.unwrap_or(48_000);
+    let mut decoder  = symphonia::default::get_codecs()
+        .make(&track.codec_params, &DecoderOptions::default())
+        .map_err(|e| AppError::Transcription(format!(
+            "local backend only supports PCM/WAV, AAC and Opus input; failed to build decoder: {e}"
+        )))?;
+
+    let mut mono = Vec::new();
+    while let Ok(packet) = format.next_packet() {
+        if packet.track_id() != track_id {
+            continue;
+        }
+        let decoded = match decoder.decode(&packet) {
+            Ok(decoded) => decoded,
+            Err(symphonia::core::errors::Error::IoError(_)) => break,
+            Err(e) => return Err(AppError::Transcription(format!("decode error: {e}"))),
+        };
+
+        let spec = *decoded.spec();
+        let mut buf = SampleBuffer::<f32>::new(decoded.capacity() as u64, spec);
+        buf.copy_interleaved_ref(decoded);
+
+        let channels = spec.channels.count().max(1);
+        mono.extend(buf.samples().chunks(channels).map(|frame| {
+            frame.iter().sum::<f32>() / channels as f32
+        }));
+    }
+
+    Ok(resample_linear(&mono, source_rate, 16_000))
+}
+
+/// Decode an Opus track straight to 16 kHz mono via libopus, sidestepping
+/// symphonia (which can demux the container but not decode Opus itself) and
+/// the linear resampler (libopus can decode directly to 16 kHz, whisper.cpp's
+/// native rate, since Opus is rate-agnostic on decode).
+fn decode_opus_track(
+    format: &mut dyn symphonia::core::formats::FormatReader,
+    track_id: u32,
+    channels: usize,
+) -> Result<Vec<f32>, AppError> {
+    use audiopus::{coder::Decoder as OpusDecoder, Channels, SampleRate};
+
+    let opus_channels = if channels >= 2 { Channels::Stereo } else { Channels::Mono };
+    let mut decoder = OpusDecoder::new(SampleRate::Hz16000, opus_channels)
+        .map_err(|e| AppError::Transcription(format!("failed to initialize Opus decoder: {e}")))?;
+
+    // 16 kHz / 2.5 ms frames is libopus's smallest frame size; 120 ms is its
+    // largest, so this comfortably covers every packet we'll be handed.
+    let max_frame_samples = 16_000 / 1000 * 120;
+    let mut pcm = vec![0f32; max_frame_samples * channels.max(1)];
+
+    let mut mono = Vec::new();
+    loop {
+        let packet = match format.next_packet() {
+            Ok(packet) => packet,
+            Err(symphonia::core::errors::Error::IoError(_)) => break,
+            Err(e) => return Err(AppError::Transcription(format!("demux error: {e}"))),
+        };
+        if packet.track_id() != track_id {
+            continue;
+        }
+
+        let samples_per_channel = decoder
+            .decode_float(Some(&packet.data), &mut pcm, false)
+            .map_err(|e| AppError::Transcription(format!("Opus decode error: {e}")))?;
+
+        if channels >= 2 {
+            mono.extend(pcm[..samples_per_channel * channels].chunks(channels).map(|frame| {
+                frame.iter().sum::<f32>() / channels as f32
+            }));
+        } else {
+            mono.extend_from_slice(&pcm[..samples_per_channel]);
+        }
+    }
+
+    Ok(mono)
+}
+
+/// Simple linear-interpolation resampler — good enough for speech, and
+/// avoids pulling in a full DSP crate for what whisper.cpp needs.
+fn resample_linear(samples: &[f32], from_rate: u32, to_rate: u32) -> Vec<f32> {
+    if from_rate == to_rate || samples.is_empty() {
+        return samples.to_vec();
+    }
+
+    let ratio    = from_rate as f64 / to_rate as f64;
+    let out_len  = (samples.len() as f64 / ratio).round() as usize;
+
+    (0..out_len)
+        .map(|i| {
+            let src_pos = i as f64 * ratio;
+            let idx     = src_pos.floor() as usize;
+            let frac    = (src_pos - idx as f64) as f32;
+            let a       = samples.get(idx).copied().unwrap_or(0.0);
+            let b       = samples.get(idx + 1).copied().unwrap_or(a);
+            a + (b - a) * frac
+        })
+        .collect()
+}
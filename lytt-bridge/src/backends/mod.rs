@@ -0,0 +1,98 @@
+pub mod deepgram;
+pub mod local;
+pub mod openai;
+
+use std::{collections::HashMap, sync::Arc};
+
+use async_trait::async_trait;
+use serde::Serialize;
+
+use crate::error::AppError;
+
+/// A single segment of a transcript with its time bounds, as returned by
+/// `response_format=verbose_json`.
+#[derive(Debug, Clone, Serialize)]
+pub struct TranscriptSegment {
+    pub start: f64,
+    pub end:   f64,
+    pub text:  String,
+}
+
+/// A single word with its time bounds, as returned when
+/// `response_format=verbose_json` asks for word-level granularity — the data
+/// a caller needs to highlight words in sync with audio playback.
+#[derive(Debug, Clone, Serialize)]
+pub struct TranscriptWord {
+    pub start: f64,
+    pub end:   f64,
+    pub word:  String,
+}
+
+/// The result of a transcription call. `language` / `duration` / `segments`
+/// / `words` are only populated when the caller asked for `verbose` output
+/// and the backend is able to provide them — a backend that can't (Deepgram)
+/// leaves them `None`.
+#[derive(Debug, Clone, Serialize, Default)]
+pub struct TranscriptionResult {
+    pub text:     String,
+    pub language: Option<String>,
+    pub duration: Option<f64>,
+    pub segments: Option<Vec<TranscriptSegment>>,
+    pub words:    Option<Vec<TranscriptWord>>,
+}
+
+impl TranscriptionResult {
+    pub fn text_only(text: String) -> Self {
+        Self { text, ..Default::default() }
+    }
+}
+
+/// A pluggable speech-to-text provider. `transcribe::handler` is agnostic to
+/// which implementation backs a given request — it only needs the decoded
+/// audio bytes, the browser-reported MIME type, an optional language hint,
+/// and whether the caller wants verbose (segment/word timestamped) output.
+#[async_trait]
+pub trait TranscriptionBackend: Send + Sync {
+    async fn transcribe(
+        &self,
+        bytes: Vec<u8>,
+        mime: &str,
+        lang: Option<&str>,
+        verbose: bool,
+    ) -> Result<TranscriptionResult, AppError>;
+}
+
+/// The set of transcription providers available at runtime, keyed by the
+/// name a caller passes in the `provider` multipart field. Only providers
+/// whose prerequisites (API key, model path, ...) were configured at startup
+/// are registered, so an unavailable provider is simply absent rather than
+/// silently falling back to another one.
+pub struct Backends {
+    registry: HashMap<&'static str, Arc<dyn TranscriptionBackend>>,
+    default:  &'static str,
+}
+
+impl Backends {
+    pub fn new(default: &'static str) -> Self {
+        Self { registry: HashMap::new(), default }
+    }
+
+    pub fn register(&mut self, name: &'static str, backend: Arc<dyn TranscriptionBackend>) {
+        self.registry.insert(name, backend);
+    }
+
+    pub fn set_default(&mut self, name: &'static str) {
+        self.default = name;
+    }
+
+    /// Resolve the backend for a request. `provider` is the optional
+    /// multipart field sent by the frontend; `None` falls back to the
+    /// server's configured default.
+    pub fn resolve(&self, provider: Option<&str>) -> Result<Arc<dyn TranscriptionBackend>, AppError> {
+        let name = provider.unwrap_or(self.default);
+        self.registry
+            .get(name)
+            .cloned()
+            .ok_or_else(|| AppError::Transcription(format!("transcription provider `{name}` is not configured")))
+    }
+}
@@ -0,0 +1,128 @@
+use async_trait::async_trait;
+use reqwest::{header::AUTHORIZATION, Client, StatusCode};
+use serde_json::Value;
+
+use crate::{error::AppError, retry::{send_with_retry, RetryPolicy}};
+
+use super::{TranscriptSegment, TranscriptWord, TranscriptionBackend, TranscriptionResult};
+
+const WHISPER_URL:   &str = "https://api.openai.com/v1/audio/transcriptions";
+const WHISPER_MODEL: &str = "whisper-1";
+
+/// Cloud transcription via OpenAI's hosted Whisper endpoint.
+pub struct OpenAiBackend {
+    http_client: Client,
+    api_key:     String,
+    retry:       RetryPolicy,
+}
+
+impl OpenAiBackend {
+    pub fn new(http_client: Client, api_key: String, retry: RetryPolicy) -> Self {
+        Self { http_client, api_key, retry }
+    }
+}
+
+#[async_trait]
+impl TranscriptionBackend for OpenAiBackend {
+    async fn transcribe(
+        &self,
+        bytes: Vec<u8>,
+        mime: &str,
+        lang: Option<&str>,
+        verbose: bool,
+    ) -> Result<TranscriptionResult, AppError> {
+        let response_format = if verbose { "verbose_json" } else { "json" };
+
+        // Validate the MIME type once upfront so the retry closure below can
+        // rebuild the (non-cloneable) multipart form on every attempt without
+        // needing to propagate a fallible result out of it.
+        reqwest::multipart::Part::bytes(Vec::new())
+            .mime_str(mime)
+            .map_err(|e| AppError::Whisper(e.to_string()))?;
+
+        let build_form = || {
+            let file_part = reqwest::multipart::Part::bytes(bytes.clone())
+                .file_name("recording.webm")
+                .mime_str(mime)
+                .expect("validated above");
+
+            let mut form = reqwest::multipart::Form::new()
+                .part("file",            file_part)
+                .text("model",           WHISPER_MODEL)
+                .text("response_format", response_format);
+
+            if verbose {
+                form = form
+                    .text("timestamp_granularities[]", "segment")
+                    .text("timestamp_granularities[]", "word");
+            }
+
+            if let Some(lang) = lang {
+                form = form.text("language", lang.to_string());
+            }
+
+            form
+        };
+
+        // Reuse the shared connection pool — no TLS handshake overhead per request.
+        let response = send_with_retry(&self.retry, || async {
+            self.http_client
+                .post(WHISPER_URL)
+                .header(AUTHORIZATION, format!("Bearer {}", self.api_key))
+                .multipart(build_form())
+                .send()
+                .await
+        })
+        .await
+        .map_err(|e| AppError::Whisper(e.to_string()))?;
+
+        if response.status() == StatusCode::TOO_MANY_REQUESTS {
+            let body = response.text().await.unwrap_or_default();
+            return Err(AppError::RateLimited(body));
+        }
+
+        if !response.status().is_success() {
+            let status = response.status();
+            let body   = response.text().await.unwrap_or_default();
+            return Err(AppError::Whisper(format!("HTTP {status}: {body}")));
+        }
+
+        let body = response
+            .json::<Value>()
+            .await
+            .map_err(|e| AppError::Whisper(e.to_string()))?;
+
+        let text = body.get("text").and_then(|v| v.as_str()).unwrap_or("").to_string();
+
+        if !verbose {
+            return Ok(TranscriptionResult::text_only(text));
+        }
+
+        let language = body.get("language").and_then(|v| v.as_str()).map(str::to_string);
+        let duration = body.get("duration").and_then(|v| v.as_f64());
+        let segments = body.get("segments").and_then(|v| v.as_array()).map(|segments| {
+            segments
+                .iter()
+                .map(|segment| TranscriptSegment {
+                    start: segment.get("start").and_then(|v| v.as_f64()).unwrap_or(0.0),
+                    end:   segment.get("end").and_then(|v| v.as_f64()).unwrap_or(0.0),
+                    text:  segment.get("text").and_then(|v| v.as_str()).unwrap_or("").trim().to_string(),
+                })
+                .collect()
+        });
+        // We requested `timestamp_granularities[]=word` above, so surface
+        // what it bought us instead of throwing it away.
+        let words = body.get("words").and_then(|v| v.as_array()).map(|words| {
+            words
+                .iter()
+                .map(|word| TranscriptWord {
+                    start: word.get("start").and_then(|v| v.as_f64()).unwrap_or(0.0),
+                    end:   word.get("end").and_then(|v| v.as_f64()).unwrap_or(0.0),
+                    word:  word.get("word").and_then(|v| v.as_str()).unwrap_or("").to_string(),
+                })
+                .collect()
+        });
+
+        Ok(TranscriptionResult { text, language, duration, segments, words })
+    }
+}
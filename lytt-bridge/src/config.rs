@@ -1,4 +1,33 @@
-use clap::Parser;
+use std::path::PathBuf;
+
+use clap::{Parser, ValueEnum};
+
+/// Which transcription provider backs `POST /transcribe`.
+#[derive(ValueEnum, Debug, Clone, Copy, PartialEq, Eq)]
+#[value(rename_all = "lowercase")]
+pub enum Backend {
+    /// Cloud transcription via OpenAI's hosted Whisper endpoint.
+    Openai,
+    /// Offline transcription via a local whisper.cpp (ggml) model.
+    Local,
+}
+
+/// Shape of the `/transcribe` JSON response.
+#[derive(ValueEnum, Debug, Clone, Copy, PartialEq, Eq)]
+#[value(rename_all = "snake_case")]
+pub enum ResponseFormat {
+    /// Just `{ "text": "..." }`.
+    Json,
+    /// The full payload — segment/word timestamps, detected language, and
+    /// audio duration — for synchronized captions.
+    VerboseJson,
+}
+
+impl ResponseFormat {
+    pub fn is_verbose(self) -> bool {
+        matches!(self, ResponseFormat::VerboseJson)
+    }
+}
 
 #[derive(Parser, Debug, Clone)]
 #[command(
@@ -9,8 +38,27 @@ use clap::Parser;
 pub struct Config {
     /// OpenAI API key used for Whisper transcription.
     /// Can also be set via the OPENAI_API_KEY environment variable.
+    /// Required when `--backend openai` (the default).
     #[arg(long, env = "OPENAI_API_KEY", hide_env_values = true)]
-    pub openai_api_key: String,
+    pub openai_api_key: Option<String>,
+
+    /// Which transcription provider to use.
+    #[arg(long, env = "LYTT_BACKEND", value_enum, default_value_t = Backend::Openai)]
+    pub backend: Backend,
+
+    /// Path to a ggml whisper.cpp model file. Required when `--backend local`.
+    #[arg(long, env = "LYTT_MODEL_PATH")]
+    pub model_path: Option<PathBuf>,
+
+    /// Deepgram API key. When set, `/transcribe` accepts `provider=deepgram`
+    /// as an alternative to OpenAI Whisper.
+    #[arg(long, env = "DEEPGRAM_API_KEY", hide_env_values = true)]
+    pub deepgram_api_key: Option<String>,
+
+    /// Default `/transcribe` response shape when the request doesn't send
+    /// its own `response_format` field.
+    #[arg(long, env = "LYTT_RESPONSE_FORMAT", value_enum, default_value_t = ResponseFormat::Json)]
+    pub response_format: ResponseFormat,
 
     /// Host address to listen on.
     #[arg(long, env = "LYTT_HOST", default_value = "127.0.0.1")]
@@ -19,20 +67,91 @@ pub struct Config {
     /// Port to listen on.
     #[arg(long, env = "LYTT_PORT", default_value_t = 3000)]
     pub port: u16,
+
+    /// Path to a file of newline-separated bearer tokens accepted on every
+    /// route except `/health`. Required unless `--insecure` is set.
+    #[arg(long, env = "LYTT_TOKENS_FILE")]
+    pub tokens_file: Option<PathBuf>,
+
+    /// How long a scoped token minted via `POST /tokens/scoped` stays valid,
+    /// in seconds. Scoped tokens live only in memory and are gone on restart.
+    #[arg(long, env = "LYTT_SCOPED_EXPIRY_SECONDS", default_value_t = 3600)]
+    pub scoped_expiry_seconds: u64,
+
+    /// PEM-encoded TLS certificate. Required unless `--insecure` is set.
+    #[arg(long, env = "LYTT_TLS_CERT_PATH")]
+    pub tls_cert_path: Option<PathBuf>,
+
+    /// PEM-encoded TLS private key. Required unless `--insecure` is set.
+    #[arg(long, env = "LYTT_TLS_KEY_PATH")]
+    pub tls_key_path: Option<PathBuf>,
+
+    /// Serve plain HTTP with no bearer-token auth. Only safe behind another
+    /// layer of access control, e.g. binding to loopback for local dev.
+    #[arg(long, env = "LYTT_INSECURE", default_value_t = false)]
+    pub insecure: bool,
+
+    /// Timeout for a single upstream request (Whisper, Deepgram, speech
+    /// synthesis), in seconds.
+    #[arg(long, env = "LYTT_REQUEST_TIMEOUT_SECS", default_value_t = 30)]
+    pub request_timeout_secs: u64,
+
+    /// Maximum number of retries for a transient upstream failure (HTTP 429
+    /// or 5xx) before giving up.
+    #[arg(long, env = "LYTT_MAX_RETRIES", default_value_t = 3)]
+    pub max_retries: u32,
+
+    /// Base delay for exponential backoff between retries, in milliseconds.
+    /// Doubles on every retry; ignored when the upstream sends `Retry-After`.
+    #[arg(long, env = "LYTT_RETRY_BASE_DELAY_MS", default_value_t = 200)]
+    pub retry_base_delay_ms: u64,
+
+    /// Maximum random jitter added to each backoff delay, in milliseconds.
+    #[arg(long, env = "LYTT_RETRY_JITTER_MS", default_value_t = 100)]
+    pub retry_jitter_ms: u64,
 }
 
 impl Config {
     pub fn validate(&self) -> anyhow::Result<()> {
-        if self.openai_api_key.trim().is_empty() {
-            anyhow::bail!(
-                "OPENAI_API_KEY is required. \
-                 Set it in your shell or in lytt-bridge/.env"
-            );
+        match self.backend {
+            Backend::Openai => {
+                if self.openai_api_key.as_deref().unwrap_or("").trim().is_empty() {
+                    anyhow::bail!(
+                        "OPENAI_API_KEY is required for --backend openai. \
+                         Set it in your shell or in lytt-bridge/.env"
+                    );
+                }
+            }
+            Backend::Local => {
+                if self.model_path.is_none() {
+                    anyhow::bail!("--model-path is required for --backend local");
+                }
+            }
         }
+
+        if !self.insecure {
+            if self.tokens_file.is_none() {
+                anyhow::bail!("--tokens-file is required unless --insecure is set");
+            }
+            if self.tls_cert_path.is_none() || self.tls_key_path.is_none() {
+                anyhow::bail!(
+                    "--tls-cert-path and --tls-key-path are required unless --insecure is set"
+                );
+            }
+        }
+
         Ok(())
     }
 
     pub fn addr(&self) -> String {
         format!("{}:{}", self.host, self.port)
     }
+
+    pub fn retry_policy(&self) -> crate::retry::RetryPolicy {
+        crate::retry::RetryPolicy {
+            max_retries: self.max_retries,
+            base_delay:  std::time::Duration::from_millis(self.retry_base_delay_ms),
+            jitter:      std::time::Duration::from_millis(self.retry_jitter_ms),
+        }
+    }
 }
@@ -17,6 +17,25 @@ pub enum AppError {
     #[error("OpenAI Whisper request failed: {0}")]
     Whisper(String),
 
+    /// Provider-neutral counterpart to [`AppError::Whisper`], for backends
+    /// (Deepgram, the local whisper.cpp model) that aren't OpenAI's hosted
+    /// Whisper endpoint — so a non-OpenAI failure doesn't surface to the
+    /// client claiming to be one.
+    #[error("Transcription request failed: {0}")]
+    Transcription(String),
+
+    #[error("Invalid `voice` field: {0}")]
+    InvalidVoice(String),
+
+    #[error("Invalid `format` field: {0}")]
+    InvalidFormat(String),
+
+    #[error("OpenAI speech synthesis request failed: {0}")]
+    Speech(String),
+
+    #[error("Rate limited by upstream provider: {0}")]
+    RateLimited(String),
+
     #[error("Internal error: {0}")]
     Internal(#[from] anyhow::Error),
 }
@@ -24,8 +43,14 @@ pub enum AppError {
 impl IntoResponse for AppError {
     fn into_response(self) -> Response {
         let status = match &self {
-            AppError::MissingAudio | AppError::Multipart(_) => StatusCode::BAD_REQUEST,
-            AppError::Whisper(_) | AppError::Internal(_) => StatusCode::INTERNAL_SERVER_ERROR,
+            AppError::MissingAudio
+            | AppError::Multipart(_)
+            | AppError::InvalidVoice(_)
+            | AppError::InvalidFormat(_) => StatusCode::BAD_REQUEST,
+            AppError::RateLimited(_) => StatusCode::TOO_MANY_REQUESTS,
+            AppError::Whisper(_) | AppError::Transcription(_) | AppError::Speech(_) | AppError::Internal(_) => {
+                StatusCode::INTERNAL_SERVER_ERROR
+            }
         };
 
         tracing::error!(error = %self);
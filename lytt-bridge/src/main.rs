@@ -1,18 +1,24 @@
 mod app_state;
+mod auth;
+mod backends;
 mod config;
 mod error;
+mod retry;
 mod routes;
 
 use std::{net::SocketAddr, sync::Arc, time::Duration};
 
 use anyhow::Context;
 use axum::{extract::DefaultBodyLimit, routing::{get, post}, Router};
+use axum_server::tls_rustls::RustlsConfig;
 use clap::Parser;
 use tower_http::{cors::CorsLayer, trace::TraceLayer};
 use tracing_subscriber::{layer::SubscriberExt, util::SubscriberInitExt};
 
 use app_state::AppState;
-use config::Config;
+use auth::{AuthLayer, AuthState};
+use backends::{deepgram::DeepgramBackend, local::LocalBackend, openai::OpenAiBackend, Backends};
+use config::{Backend, Config};
 
 #[tokio::main]
 async fn main() -> anyhow::Result<()> {
@@ -30,36 +36,108 @@ async fn main() -> anyhow::Result<()> {
     let config = Config::parse();
     config.validate().context("Invalid configuration")?;
 
-    // Build a shared HTTP client with a connection pool and a timeout that
-    // exceeds the longest Whisper transcription we'd ever expect (30 s).
+    // Build a shared HTTP client with a connection pool. The timeout covers
+    // a single request/response round trip, not the retries layered on top
+    // of it in each backend.
     let http_client = reqwest::Client::builder()
-        .timeout(Duration::from_secs(30))
+        .timeout(Duration::from_secs(config.request_timeout_secs))
         .build()
         .context("Failed to build HTTP client")?;
 
     let addr: SocketAddr = config.addr().parse().context("Invalid bind address")?;
-    let state = Arc::new(AppState { config, http_client });
+    let retry_policy = config.retry_policy();
+
+    // Register every provider whose prerequisites were configured, so a
+    // per-request `provider` override can reach it even when it isn't the
+    // server's configured default.
+    let mut backends = Backends::new("openai");
+
+    if let Some(api_key) = config.openai_api_key.clone() {
+        backends.register("openai", Arc::new(OpenAiBackend::new(http_client.clone(), api_key, retry_policy)));
+    }
+
+    if let Some(api_key) = config.deepgram_api_key.clone() {
+        backends.register("deepgram", Arc::new(DeepgramBackend::new(http_client.clone(), api_key, retry_policy)));
+    }
+
+    if let Some(model_path) = config.model_path.as_ref() {
+        backends.register(
+            "local",
+            Arc::new(LocalBackend::load(model_path).context("Failed to load local whisper model")?),
+        );
+    }
+
+    if config.backend == Backend::Local {
+        backends.set_default("local");
+    }
+
+    // `--insecure` is the only way to run without the auth layer — by
+    // default lytt-bridge refuses to serve a single authenticated route
+    // without it (enforced in `Config::validate`).
+    let auth = if config.insecure {
+        None
+    } else {
+        let tokens_file = config.tokens_file.as_ref().expect("validated by Config::validate");
+        Some(Arc::new(AuthState::load(
+            tokens_file,
+            Duration::from_secs(config.scoped_expiry_seconds),
+        )?))
+    };
+
+    let insecure  = config.insecure;
+    let tls_paths = (config.tls_cert_path.clone(), config.tls_key_path.clone());
+
+    let state = Arc::new(AppState { config, http_client, backends, auth: auth.clone() });
+
+    let mut protected = Router::new()
+        .route("/transcribe",    post(routes::transcribe::handler))
+        .route("/speak",         post(routes::speak::handler))
+        .route("/stream",        get(routes::stream::handler))
+        .route("/tokens/scoped", post(routes::tokens::mint));
+
+    if let Some(auth) = auth {
+        protected = protected.layer(AuthLayer::new(auth));
+    }
 
     let app = Router::new()
-        .route("/health",     get(routes::health::handler))
-        .route("/transcribe", post(routes::transcribe::handler))
+        .route("/health", get(routes::health::handler))
+        .merge(protected)
         // 26 MB body limit — 1 MB headroom above OpenAI Whisper's 25 MB hard cap.
         .layer(DefaultBodyLimit::max(26 * 1024 * 1024))
-        // Allow the frontend (any local origin) to reach this localhost server.
+        // Allow the frontend (any origin) to reach this server.
         .layer(CorsLayer::permissive())
         .layer(TraceLayer::new_for_http())
         .with_state(state);
 
-    tracing::info!("lytt-bridge listening on http://{}", addr);
+    if insecure {
+        tracing::warn!("Running with --insecure: no auth, plain HTTP");
 
-    let listener = tokio::net::TcpListener::bind(addr)
-        .await
-        .context("Failed to bind to address")?;
+        let listener = tokio::net::TcpListener::bind(addr)
+            .await
+            .context("Failed to bind to address")?;
 
-    axum::serve(listener, app)
-        .with_graceful_shutdown(shutdown_signal())
-        .await
-        .context("Server error")?;
+        tracing::info!("lytt-bridge listening on http://{}", addr);
+
+        axum::serve(listener, app)
+            .with_graceful_shutdown(shutdown_signal())
+            .await
+            .context("Server error")?;
+    } else {
+        let (cert_path, key_path) = tls_paths;
+        let cert_path = cert_path.expect("validated by Config::validate");
+        let key_path  = key_path.expect("validated by Config::validate");
+
+        let tls_config = RustlsConfig::from_pem_file(cert_path, key_path)
+            .await
+            .context("Failed to load TLS certificate/key")?;
+
+        tracing::info!("lytt-bridge listening on https://{}", addr);
+
+        axum_server::bind_rustls(addr, tls_config)
+            .serve(app.into_make_service())
+            .await
+            .context("Server error")?;
+    }
 
     Ok(())
 }
@@ -0,0 +1,69 @@
+use std::{future::Future, time::Duration};
+
+use rand::Rng;
+use reqwest::{Response, StatusCode};
+
+/// Exponential backoff with jitter for transient upstream failures (HTTP 429
+/// and 5xx). Shared by every backend that talks to a remote provider over
+/// `reqwest`.
+#[derive(Debug, Clone, Copy)]
+pub struct RetryPolicy {
+    pub max_retries: u32,
+    pub base_delay:  Duration,
+    pub jitter:      Duration,
+}
+
+impl RetryPolicy {
+    fn backoff_delay(&self, attempt: u32) -> Duration {
+        let exponential = self.base_delay.saturating_mul(1u32 << attempt.min(16));
+        let jitter_ms    = rand::thread_rng().gen_range(0..=self.jitter.as_millis() as u64);
+        exponential + Duration::from_millis(jitter_ms)
+    }
+}
+
+fn is_retryable(status: StatusCode) -> bool {
+    status == StatusCode::TOO_MANY_REQUESTS || status.is_server_error()
+}
+
+/// Honor a `Retry-After` header (seconds, or an HTTP date — we only bother
+/// with the common seconds form) when the upstream sends one, falling back
+/// to our own exponential backoff otherwise.
+fn retry_after(response: &Response) -> Option<Duration> {
+    response
+        .headers()
+        .get(reqwest::header::RETRY_AFTER)?
+        .to_str()
+        .ok()?
+        .trim()
+        .parse::<u64>()
+        .ok()
+        .map(Duration::from_secs)
+}
+
+/// Re-issue `send` (a closure that builds and fires one request, since a
+/// `reqwest::multipart::Form` can't be cloned and replayed) until it
+/// succeeds, returns a non-retryable status, or `policy.max_retries` is
+/// exhausted. Network-level errors (timeouts, connection resets) are not
+/// retried here — they bubble up immediately to the caller.
+pub async fn send_with_retry<F, Fut>(policy: &RetryPolicy, mut send: F) -> reqwest::Result<Response>
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = reqwest::Result<Response>>,
+{
+    let mut attempt = 0;
+
+    loop {
+        let response = send().await?;
+        let status = response.status();
+
+        if !is_retryable(status) || attempt >= policy.max_retries {
+            return Ok(response);
+        }
+
+        let delay = retry_after(&response).unwrap_or_else(|| policy.backoff_delay(attempt));
+        attempt += 1;
+
+        tracing::warn!(%status, attempt, delay_ms = delay.as_millis(), "Transient upstream failure, retrying");
+        tokio::time::sleep(delay).await;
+    }
+}
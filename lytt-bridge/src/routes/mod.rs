@@ -0,0 +1,5 @@
+pub mod health;
+pub mod speak;
+pub mod stream;
+pub mod tokens;
+pub mod transcribe;
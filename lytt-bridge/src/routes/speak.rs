@@ -0,0 +1,104 @@
+use std::sync::Arc;
+
+use axum::{
+    body::Bytes,
+    extract::State,
+    http::header::CONTENT_TYPE,
+    response::{IntoResponse, Response},
+    Json,
+};
+use reqwest::header::AUTHORIZATION;
+use serde::Deserialize;
+
+use crate::{app_state::AppState, error::AppError};
+
+const SPEECH_URL:   &str = "https://api.openai.com/v1/audio/speech";
+const SPEECH_MODEL: &str = "tts-1";
+
+#[derive(Debug, Deserialize)]
+pub struct SpeakRequest {
+    text: String,
+    #[serde(default = "default_voice")]
+    voice: String,
+    #[serde(default = "default_format")]
+    format: String,
+}
+
+fn default_voice() -> String {
+    "alloy".to_string()
+}
+
+fn default_format() -> String {
+    "mp3".to_string()
+}
+
+/// Map a requested `format` to the `Content-Type` of the audio OpenAI's
+/// speech API returns for it, or `None` if `format` isn't one it supports.
+fn content_type_for(format: &str) -> Option<&'static str> {
+    match format {
+        "mp3"  => Some("audio/mpeg"),
+        "opus" => Some("audio/opus"),
+        "aac"  => Some("audio/aac"),
+        "flac" => Some("audio/flac"),
+        "wav"  => Some("audio/wav"),
+        "pcm"  => Some("audio/pcm"),
+        _ => None,
+    }
+}
+
+fn validate_voice(voice: &str) -> Result<(), AppError> {
+    const VOICES: &[&str] = &["alloy", "echo", "fable", "onyx", "nova", "shimmer"];
+    if VOICES.contains(&voice) {
+        Ok(())
+    } else {
+        Err(AppError::InvalidVoice(format!(
+            "`{voice}` is not one of {VOICES:?}"
+        )))
+    }
+}
+
+pub async fn handler(
+    State(state): State<Arc<AppState>>,
+    Json(req): Json<SpeakRequest>,
+) -> Result<Response, AppError> {
+    validate_voice(&req.voice)?;
+
+    let content_type = content_type_for(&req.format).ok_or_else(|| {
+        AppError::InvalidFormat(format!("`{}` is not a supported audio format", req.format))
+    })?;
+
+    tracing::debug!(chars = req.text.len(), voice = %req.voice, format = %req.format, "Requesting speech synthesis");
+
+    let api_key = state.config.openai_api_key.as_deref().ok_or_else(|| {
+        AppError::Speech("OPENAI_API_KEY is required for /speak".to_string())
+    })?;
+
+    // Reuse the shared connection pool — no TLS handshake overhead per request.
+    let response = state.http_client
+        .post(SPEECH_URL)
+        .header(AUTHORIZATION, format!("Bearer {api_key}"))
+        .json(&serde_json::json!({
+            "model":           SPEECH_MODEL,
+            "input":           req.text,
+            "voice":           req.voice,
+            "response_format": req.format,
+        }))
+        .send()
+        .await
+        .map_err(|e| AppError::Speech(e.to_string()))?;
+
+    if !response.status().is_success() {
+        let status = response.status();
+        let body   = response.text().await.unwrap_or_default();
+        return Err(AppError::Speech(format!("HTTP {status}: {body}")));
+    }
+
+    let audio: Bytes = response
+        .bytes()
+        .await
+        .map_err(|e| AppError::Speech(e.to_string()))?;
+
+    tracing::info!(bytes = audio.len(), "Speech synthesis complete");
+
+    Ok(([(CONTENT_TYPE, content_type)], audio).into_response())
+}
@@ -0,0 +1,229 @@
+use std::{sync::Arc, time::Duration};
+
+use axum::{
+    extract::{
+        ws::{Message, WebSocket, WebSocketUpgrade},
+        State,
+    },
+    response::Response,
+};
+use serde::Deserialize;
+use serde_json::json;
+
+use crate::app_state::AppState;
+
+/// How often new audio is transcribed into a partial result when the client
+/// isn't explicitly sending `{"type":"flush"}`.
+const PARTIAL_CADENCE: Duration = Duration::from_secs(2);
+
+#[derive(Debug, Deserialize)]
+#[serde(tag = "type", rename_all = "lowercase")]
+enum ControlFrame {
+    Start {
+        #[serde(default = "default_mime")]
+        mime: String,
+        language: Option<String>,
+    },
+    Flush,
+    Stop,
+}
+
+fn default_mime() -> String {
+    "audio/webm".to_string()
+}
+
+/// Per-connection state for one `/stream` dictation session. Distinct from
+/// [`AppState`] — that's shared across every connection, this lives only as
+/// long as a single WebSocket does.
+///
+/// Re-transcribing the whole session on every tick would mean re-uploading
+/// every prior second of audio on every later one (O(n²) against the cloud
+/// backends). Instead only `pending` — the audio received since the last
+/// transcription — grows unboundedly within a tick window; once it's sent it
+/// is cleared and its text is folded into `committed_text`. `header` is kept
+/// around and resent with every request because MediaRecorder only emits the
+/// container's init segment in the first chunk — without it, later chunks
+/// aren't independently decodable. That first chunk, though, also carries the
+/// first slice of real audio, so `header` isn't transcript-silent: its own
+/// contribution is transcribed once into `header_transcript` and stripped
+/// back out of every later `header + pending` result, instead of being
+/// re-appended to `committed_text` on every single tick.
+struct ConnectionState {
+    header:             Option<Vec<u8>>,
+    header_transcript:  Option<String>,
+    pending:            Vec<u8>,
+    committed_text:     String,
+    dirty:              bool,
+    mime:               String,
+    language:           Option<String>,
+    active:             bool,
+}
+
+impl ConnectionState {
+    fn new() -> Self {
+        Self {
+            header:            None,
+            header_transcript: None,
+            pending:           Vec::new(),
+            committed_text:    String::new(),
+            dirty:             false,
+            mime:              default_mime(),
+            language:          None,
+            active:            false,
+        }
+    }
+
+    fn reset(&mut self) {
+        self.header = None;
+        self.header_transcript = None;
+        self.pending.clear();
+        self.committed_text.clear();
+        self.dirty  = false;
+        self.active = false;
+    }
+
+    /// Append newly-transcribed text to the running transcript, space-joined
+    /// like words normally are.
+    fn commit(&mut self, text: &str) {
+        if text.is_empty() {
+            return;
+        }
+        if !self.committed_text.is_empty() {
+            self.committed_text.push(' ');
+        }
+        self.committed_text.push_str(text);
+    }
+}
+
+pub async fn handler(ws: WebSocketUpgrade, State(state): State<Arc<AppState>>) -> Response {
+    ws.on_upgrade(move |socket| handle_socket(socket, state))
+}
+
+async fn handle_socket(mut socket: WebSocket, state: Arc<AppState>) {
+    let mut conn = ConnectionState::new();
+    let mut cadence = tokio::time::interval(PARTIAL_CADENCE);
+    cadence.tick().await; // first tick fires immediately; discard it
+
+    loop {
+        tokio::select! {
+            frame = socket.recv() => {
+                match frame {
+                    Some(Ok(Message::Binary(bytes))) => {
+                        if conn.active {
+                            if conn.header.is_none() {
+                                conn.header = Some(bytes.to_vec());
+                            } else {
+                                conn.pending.extend_from_slice(&bytes);
+                            }
+                            conn.dirty = true;
+                        }
+                    }
+                    Some(Ok(Message::Text(text))) => {
+                        match serde_json::from_str::<ControlFrame>(&text) {
+                            Ok(ControlFrame::Start { mime, language }) => {
+                                conn.reset();
+                                conn.mime     = mime;
+                                conn.language = language;
+                                conn.active   = true;
+                            }
+                            Ok(ControlFrame::Flush) => {
+                                send_transcript(&mut socket, &state, &mut conn, "partial").await;
+                            }
+                            Ok(ControlFrame::Stop) => {
+                                send_transcript(&mut socket, &state, &mut conn, "final").await;
+                                conn.reset();
+                            }
+                            Err(e) => {
+                                let _ = send_json(&mut socket, &json!({ "type": "error", "message": e.to_string() })).await;
+                            }
+                        }
+                    }
+                    Some(Ok(Message::Close(_))) | None => break,
+                    Some(Ok(_)) => { /* ping/pong handled by axum */ }
+                    Some(Err(e)) => {
+                        tracing::warn!(error = %e, "WebSocket error on /stream");
+                        break;
+                    }
+                }
+            }
+
+            _ = cadence.tick() => {
+                if conn.active && conn.dirty {
+                    send_transcript(&mut socket, &state, &mut conn, "partial").await;
+                }
+            }
+        }
+    }
+}
+
+/// Transcribe whatever's new since the last call (if anything), fold the
+/// result into `conn.committed_text`, and send the running transcript to the
+/// client. When there's nothing new to transcribe it only re-sends the
+/// current `committed_text` under `kind`, and sends nothing if that's empty.
+async fn send_transcript(socket: &mut WebSocket, state: &Arc<AppState>, conn: &mut ConnectionState, kind: &str) {
+    if conn.dirty {
+        let backend = match state.backends.resolve(None) {
+            Ok(backend) => backend,
+            Err(e) => {
+                let _ = send_json(socket, &json!({ "type": "error", "message": e.to_string() })).await;
+                return;
+            }
+        };
+
+        // The very first chunk carries both the container's init segment and
+        // the first slice of real audio. Learn what that slice transcribes to
+        // on its own, once, so it can be told apart from new audio below
+        // instead of being re-committed on every tick it's resent with.
+        if conn.header_transcript.is_none() {
+            if let Some(header) = conn.header.clone() {
+                match backend.transcribe(header, &conn.mime, conn.language.as_deref(), false).await {
+                    Ok(result) => {
+                        let text = result.text.trim().to_string();
+                        conn.commit(&text);
+                        conn.header_transcript = Some(text);
+                    }
+                    Err(e) => {
+                        let _ = send_json(socket, &json!({ "type": "error", "message": e.to_string() })).await;
+                        return;
+                    }
+                }
+            }
+        }
+
+        if !conn.pending.is_empty() {
+            let mut audio = conn.header.clone().unwrap_or_default();
+            audio.extend_from_slice(&conn.pending);
+
+            match backend.transcribe(audio, &conn.mime, conn.language.as_deref(), false).await {
+                Ok(result) => {
+                    let full = result.text.trim();
+                    // `full` covers the header's own audio again plus this
+                    // tick's new audio — strip the part we already committed
+                    // above so it isn't duplicated in `committed_text`.
+                    let delta = match conn.header_transcript.as_deref() {
+                        Some(prefix) if !prefix.is_empty() => {
+                            full.strip_prefix(prefix).map(str::trim_start).unwrap_or(full)
+                        }
+                        _ => full,
+                    };
+                    conn.commit(delta);
+                    conn.pending.clear();
+                }
+                Err(e) => {
+                    let _ = send_json(socket, &json!({ "type": "error", "message": e.to_string() })).await;
+                    return;
+                }
+            }
+        }
+
+        conn.dirty = false;
+    } else if conn.committed_text.is_empty() {
+        return;
+    }
+
+    let _ = send_json(socket, &json!({ "type": kind, "text": conn.committed_text })).await;
+}
+
+async fn send_json(socket: &mut WebSocket, value: &serde_json::Value) -> Result<(), axum::Error> {
+    socket.send(Message::Text(value.to_string())).await
+}
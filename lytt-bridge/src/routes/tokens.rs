@@ -0,0 +1,24 @@
+use std::sync::Arc;
+
+use axum::{extract::State, Json};
+use serde_json::{json, Value};
+
+use crate::{app_state::AppState, error::AppError};
+
+/// Mint a scoped token good for `--scoped-expiry-seconds`. Requires an
+/// already-valid bearer token (checked by [`crate::auth::AuthLayer`] before
+/// this handler runs), so only someone holding a persisted or scoped token
+/// can mint another.
+pub async fn mint(State(state): State<Arc<AppState>>) -> Result<Json<Value>, AppError> {
+    let auth = state
+        .auth
+        .as_ref()
+        .ok_or_else(|| AppError::Internal(anyhow::anyhow!("auth is disabled (--insecure)")))?;
+
+    let (token, ttl) = auth.mint_scoped();
+
+    Ok(Json(json!({
+        "token":              token,
+        "expires_in_seconds": ttl.as_secs(),
+    })))
+}
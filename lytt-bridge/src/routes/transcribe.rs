@@ -4,13 +4,10 @@ use axum::{
     extract::{Multipart, State},
     Json,
 };
-use reqwest::header::AUTHORIZATION;
+use clap::ValueEnum;
 use serde_json::{json, Value};
 
-use crate::{app_state::AppState, error::AppError};
-
-const WHISPER_URL:   &str = "https://api.openai.com/v1/audio/transcriptions";
-const WHISPER_MODEL: &str = "whisper-1";
+use crate::{app_state::AppState, config::ResponseFormat, error::AppError};
 
 /// Map the language string sent by the frontend to an ISO 639-1 code
 /// accepted by the Whisper API, or `None` to let Whisper auto-detect.
@@ -30,9 +27,10 @@ pub async fn handler(
     mut multipart: Multipart,
 ) -> Result<Json<Value>, AppError> {
     let mut audio_bytes:    Option<Vec<u8>> = None;
-    let mut filename        = "recording.webm".to_string();
     let mut audio_mime_type = "audio/webm".to_string();
     let mut raw_language:   Option<String>  = None;
+    let mut provider:       Option<String>  = None;
+    let mut raw_response_format: Option<String> = None;
 
     while let Some(field) = multipart
         .next_field()
@@ -41,11 +39,6 @@ pub async fn handler(
     {
         match field.name() {
             Some("file") => {
-                filename = field
-                    .file_name()
-                    .unwrap_or("recording.webm")
-                    .to_string();
-
                 // Capture MIME type before consuming the field (axum moves it on .bytes()).
                 // The browser sets this from blob.type, so it reflects the actual codec
                 // (audio/webm, audio/mp4, etc.) rather than a hardcoded assumption.
@@ -71,55 +64,64 @@ pub async fn handler(
                 );
             }
 
+            Some("provider") => {
+                provider = Some(
+                    field
+                        .text()
+                        .await
+                        .map_err(|e| AppError::Multipart(e.to_string()))?,
+                );
+            }
+
+            Some("response_format") => {
+                raw_response_format = Some(
+                    field
+                        .text()
+                        .await
+                        .map_err(|e| AppError::Multipart(e.to_string()))?,
+                );
+            }
+
             _ => { /* ignore unexpected fields */ }
         }
     }
 
-    let bytes    = audio_bytes.ok_or(AppError::MissingAudio)?;
+    let bytes   = audio_bytes.ok_or(AppError::MissingAudio)?;
     let language = raw_language.as_deref().and_then(normalize_language);
-
-    tracing::debug!(bytes = bytes.len(), mime = %audio_mime_type, ?language, "Sending audio to Whisper");
-
-    // Build the multipart form for the OpenAI Whisper endpoint.
-    let file_part = reqwest::multipart::Part::bytes(bytes)
-        .file_name(filename)
-        .mime_str(&audio_mime_type)
-        .map_err(|e| AppError::Whisper(e.to_string()))?;
-
-    let mut form = reqwest::multipart::Form::new()
-        .part("file",            file_part)
-        .text("model",           WHISPER_MODEL)
-        .text("response_format", "json");
-
-    if let Some(lang) = language {
-        form = form.text("language", lang);
-    }
-
-    // Reuse the shared connection pool â€” no TLS handshake overhead per request.
-    let response = state.http_client
-        .post(WHISPER_URL)
-        .header(AUTHORIZATION, format!("Bearer {}", state.config.openai_api_key))
-        .multipart(form)
-        .send()
-        .await
-        .map_err(|e| AppError::Whisper(e.to_string()))?;
-
-    if !response.status().is_success() {
-        let status = response.status();
-        let body   = response.text().await.unwrap_or_default();
-        return Err(AppError::Whisper(format!("HTTP {status}: {body}")));
-    }
-
-    let text = response
-        .json::<Value>()
-        .await
-        .map_err(|e| AppError::Whisper(e.to_string()))?
-        .get("text")
-        .and_then(|v| v.as_str())
-        .unwrap_or("")
-        .to_string();
-
-    tracing::info!(chars = text.len(), "Transcription complete");
-
-    Ok(Json(json!({ "text": text })))
+    let backend  = state.backends.resolve(provider.as_deref())?;
+
+    let response_format = match raw_response_format.as_deref() {
+        Some(raw) => ResponseFormat::from_str(raw, true)
+            .map_err(|_| AppError::Multipart(format!("invalid response_format `{raw}`")))?,
+        None => state.config.response_format,
+    };
+
+    tracing::debug!(
+        bytes = bytes.len(),
+        mime = %audio_mime_type,
+        ?language,
+        provider = ?provider,
+        verbose = response_format.is_verbose(),
+        "Sending audio to transcription backend",
+    );
+
+    let result = backend
+        .transcribe(bytes, &audio_mime_type, language, response_format.is_verbose())
+        .await?;
+
+    tracing::info!(chars = result.text.len(), "Transcription complete");
+
+    let body = if response_format.is_verbose() {
+        json!({
+            "text":     result.text,
+            "language": result.language,
+            "duration": result.duration,
+            "segments": result.segments,
+            "words":    result.words,
+        })
+    } else {
+        json!({ "text": result.text })
+    };
+
+    Ok(Json(body))
 }